@@ -0,0 +1,122 @@
+use tauri::plugin::{Builder, TauriPlugin};
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_fs::FsExt;
+
+/// Request payload for [`generate_captions`]; will grow as the real
+/// captioning pipeline (model choice, style, language, ...) lands.
+#[derive(Debug, serde::Deserialize)]
+pub struct GenerateCaptionsRequest {
+  pub project_path: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct GenerateCaptionsResponse {
+  pub captions: Vec<String>,
+}
+
+#[tauri::command]
+async fn generate_captions(
+  request: GenerateCaptionsRequest,
+) -> Result<GenerateCaptionsResponse, String> {
+  log::info!(
+    "generating captions for project at {}",
+    request.project_path
+  );
+  // TODO: wire up the real captioning pipeline.
+  Ok(GenerateCaptionsResponse {
+    captions: Vec::new(),
+  })
+}
+
+#[tauri::command]
+async fn export_captions(project_path: String, captions: Vec<String>) -> Result<(), String> {
+  log::info!(
+    "exporting {} caption(s) for project at {}",
+    captions.len(),
+    project_path
+  );
+  // TODO: write the exported captions alongside the project via the fs plugin.
+  Ok(())
+}
+
+// Both of these check the path against the `fs` plugin's scope before
+// touching `std::fs`, so a webview caller can't read or overwrite an
+// arbitrary absolute path outside the capabilities granted to the app. The
+// actual IO is split into `read_file_contents`/`write_file_contents` below so
+// it's testable without needing a running app to grant the scope.
+#[tauri::command]
+async fn read_project_file<R: Runtime>(app: AppHandle<R>, path: String) -> Result<String, String> {
+  let path = std::path::Path::new(&path);
+  if !app.fs_scope().is_allowed(path) {
+    return Err(format!("'{}' is outside the app's fs scope", path.display()));
+  }
+  read_file_contents(path)
+}
+
+#[tauri::command]
+async fn write_project_file<R: Runtime>(
+  app: AppHandle<R>,
+  path: String,
+  contents: String,
+) -> Result<(), String> {
+  let path = std::path::Path::new(&path);
+  if !app.fs_scope().is_allowed(path) {
+    return Err(format!("'{}' is outside the app's fs scope", path.display()));
+  }
+  write_file_contents(path, contents)
+}
+
+fn read_file_contents(path: &std::path::Path) -> Result<String, String> {
+  std::fs::read_to_string(path).map_err(|e| format!("failed to read '{}': {e}", path.display()))
+}
+
+fn write_file_contents(path: &std::path::Path, contents: String) -> Result<(), String> {
+  std::fs::write(path, contents).map_err(|e| format!("failed to write '{}': {e}", path.display()))
+}
+
+/// Registers CaptionPilot's core operations (generating/exporting captions,
+/// reading/writing project files) as a dedicated Tauri plugin, so the
+/// frontend gets a typed API instead of doing everything in JS. Register
+/// with `.plugin(commands::init())` in `run()`.
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+  Builder::new("caption")
+    .invoke_handler(tauri::generate_handler![
+      generate_captions,
+      export_captions,
+      read_project_file,
+      write_project_file,
+    ])
+    .build()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("captionpilot-commands-test-{name}-{}", std::process::id()))
+  }
+
+  #[test]
+  fn write_then_read_round_trips_contents() {
+    let path = temp_path("round-trip");
+    write_file_contents(&path, "hello caption".to_string()).expect("write should succeed");
+    let contents = read_file_contents(&path).expect("read should succeed");
+    assert_eq!(contents, "hello caption");
+    std::fs::remove_file(&path).ok();
+  }
+
+  #[test]
+  fn read_missing_file_returns_error() {
+    let path = temp_path("does-not-exist");
+    let err = read_file_contents(&path).expect_err("missing file should error");
+    assert!(err.contains("failed to read"));
+  }
+
+  #[test]
+  fn write_into_missing_directory_returns_error() {
+    let path = temp_path("missing-parent").join("nested").join("file.txt");
+    let err = write_file_contents(&path, "data".to_string()).expect_err("should error");
+    assert!(err.contains("failed to write"));
+  }
+}
@@ -1,5 +1,85 @@
+use std::str::FromStr;
+
+mod commands;
+
 use tauri::Manager;
-use tauri_plugin_log::{Builder as LogBuilder, Target, TargetKind};
+use tauri_plugin_log::fern::colors::ColoredLevelConfig;
+use tauri_plugin_log::{Builder as LogBuilder, RotationStrategy, Target, TargetKind};
+use time::macros::format_description;
+
+/// `year-month-day hour:minute:second`, evaluated in the machine's local
+/// offset (the plugin's `time` dependency needs the `local-offset` feature
+/// for that) so log lines line up with what the user sees on their clock.
+const LOG_TIMESTAMP_FORMAT: &[time::format_description::BorrowedFormatItem] =
+  format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+
+/// Timestamp + level + originating module, so a pasted log line is greppable
+/// on its own. `colors` colorizes the level with ANSI escapes for an
+/// interactive target (stdout); pass `None` for targets like the log file
+/// that need to stay plain-text.
+// TODO: render `record.key_values()` once CaptionPilot actually attaches
+// structured fields to a log call; nothing does yet.
+fn format_log_line(
+  message: &std::fmt::Arguments,
+  record: &log::Record,
+  colors: Option<&ColoredLevelConfig>,
+) -> String {
+  let timestamp = time::OffsetDateTime::now_local()
+    .unwrap_or_else(|_| time::OffsetDateTime::now_utc())
+    .format(LOG_TIMESTAMP_FORMAT)
+    .unwrap_or_default();
+  let level = match colors {
+    Some(colors) => colors.color(record.level()).to_string(),
+    None => record.level().to_string(),
+  };
+  format!("[{timestamp}][{level}][{}] {message}", record.target())
+}
+
+/// Cap on the `logs` file before it gets rotated, so a long-running session
+/// can't slowly fill the user's disk. Adjust here if CaptionPilot ever needs
+/// chattier (or quieter) logging by default.
+const LOG_MAX_FILE_SIZE: u128 = byte_unit::n_mb_bytes!(5);
+
+/// How many rotated log files to keep around alongside the current one.
+const LOG_MAX_ROTATED_FILES: usize = 5;
+
+/// Env var a user (or our own launcher scripts) can set to override the
+/// startup log level, e.g. `CAPTIONPILOT_LOG=trace` to capture detail before
+/// reproducing a bug, without needing a debug build.
+const LOG_LEVEL_ENV_VAR: &str = "CAPTIONPILOT_LOG";
+
+/// Resolves the level to start logging at: `CAPTIONPILOT_LOG` wins if set and
+/// valid, otherwise `Debug` in dev builds and `Info` in release.
+fn initial_log_level() -> log::LevelFilter {
+  std::env::var(LOG_LEVEL_ENV_VAR)
+    .ok()
+    .and_then(|level| log::LevelFilter::from_str(&level).ok())
+    .unwrap_or(if cfg!(debug_assertions) {
+      log::LevelFilter::Debug
+    } else {
+      log::LevelFilter::Info
+    })
+}
+
+/// Raises or lowers verbosity at runtime, so the frontend settings UI can
+/// turn on trace logging to capture a bug report without a restart.
+///
+/// `fern::Dispatch` (what `LogBuilder` builds on top of) bakes in whatever
+/// level `.level()` was given at startup and re-checks every record against
+/// *that*, independent of the global max level `log::set_max_level` sets —
+/// if the dispatch were built at the restrictive `initial_log_level()`, this
+/// command could only ever tighten things, never raise verbosity back up,
+/// since records above that baked-in level get dropped inside fern before
+/// `set_max_level` is even consulted. `run()` works around this by building
+/// the dispatch itself wide open (`.level(Trace)`) and relying solely on the
+/// mutable global max level — set once from `initial_log_level()` in
+/// `setup()`, and freely raised or lowered from here — as the real gate.
+#[tauri::command]
+fn set_log_level(level: String) -> Result<(), String> {
+  log::LevelFilter::from_str(&level)
+    .map(log::set_max_level)
+    .map_err(|e| format!("invalid log level '{level}': {e}"))
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -10,20 +90,69 @@ pub fn run() {
                     let window = app.get_webview_window("main").unwrap();
                     window.open_devtools();
                   }
+        // The dispatch below is built maximally permissive; this is the
+        // level that actually takes effect at startup, and `set_log_level`
+        // can move it up or down from here without a restart.
+        log::set_max_level(initial_log_level());
         Ok(())
     })
     .plugin(
           LogBuilder::default()
-            .level(log::LevelFilter::Debug)          // adjust as needed
-            .target(Target::new(
-                TargetKind::LogDir {
+            // Wide open on purpose — see the `set_log_level` doc comment for
+            // why the real level lives in the global max level instead.
+            .level(log::LevelFilter::Trace)
+            // Once `logs` crosses LOG_MAX_FILE_SIZE, roll it into a numbered
+            // file and keep only the last LOG_MAX_ROTATED_FILES around, so
+            // total disk usage from logging stays bounded over a long-running
+            // session instead of growing forever (`KeepAll`) or throwing away
+            // everything but the single most recent rotation (`KeepOne`).
+            .max_file_size(LOG_MAX_FILE_SIZE)
+            .rotation_strategy(RotationStrategy::KeepSome(LOG_MAX_ROTATED_FILES))
+            // Builder-level formatter: used by any target that doesn't set
+            // its own (the log file, the webview). Plain text so the file
+            // stays greppable.
+            .format(|out, message, record| {
+              out.finish(format_args!("{}", format_log_line(message, record, None)))
+            })
+            // `.targets()` *replaces* the whole target list rather than
+            // appending, so the always-on "logs" file target has to be built
+            // into this same vec alongside the dev-only ones instead of
+            // chaining a separate `.target(...)` call above.
+            //
+            // Mirror backend logs into stdout and the webview console so a
+            // live log console in the UI (and `cargo tauri dev`) can follow
+            // along. These are noisy for a packaged release, so keep them
+            // dev-only; the file target stays in both. Stdout gets its own
+            // `.format()` (`Target::format` applies per-target, independent
+            // of the builder-level one above) so only the terminal, not the
+            // log file, gets ANSI-colored levels.
+            // The other half of this (attaching the plugin's JS `attachLogger`
+            // / `attachConsole` so frontend trace/debug/info/warn/error calls
+            // merge into the same stream) belongs in the frontend source tree,
+            // which doesn't exist in this checkout yet — nothing to wire it
+            // into on the Rust side until that lands.
+            .targets({
+                let mut targets = vec![Target::new(TargetKind::LogDir {
                   file_name: Some("logs".to_string()),
-                },
-              ))
+                })];
+                if cfg!(debug_assertions) {
+                  let stdout_colors = ColoredLevelConfig::default();
+                  targets.push(Target::new(TargetKind::Stdout).format(move |out, message, record| {
+                    out.finish(format_args!(
+                      "{}",
+                      format_log_line(message, record, Some(&stdout_colors))
+                    ))
+                  }));
+                  targets.push(Target::new(TargetKind::Webview));
+                }
+                targets
+              })
             .build()
         )
     .plugin(tauri_plugin_fs::init())
     .plugin(tauri_plugin_dialog::init())
+    .plugin(commands::init())
+    .invoke_handler(tauri::generate_handler![set_log_level])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }